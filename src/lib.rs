@@ -1,3 +1,4 @@
+use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
 
 enum Entry<T> {
@@ -9,6 +10,53 @@ struct Node<T> {
     data: Entry<T>,
     prev: usize,
     next: usize,
+    /// Bumped every time this slot transitions `Vacant` -> `Occupied`.
+    generation: u64,
+}
+
+/// An opaque, generation-checked reference to an element.
+///
+/// A `Handle` is returned by the `push`/`insert` methods and can be used with
+/// [`Listor::get`], [`Listor::get_mut`] and [`Listor::remove`]. Unlike a bare
+/// index, a handle does not silently alias a different element once its slot has
+/// been removed and reused: the stored generation will no longer match and the
+/// lookup returns `None` (or panics, for the indexing operators).
+///
+/// # Example
+///
+/// ```rust
+/// use listor::Listor;
+///
+/// let mut listor = Listor::new();
+///
+/// let handle = listor.push_back(5).unwrap();
+/// assert_eq!(Some(&5), listor.get(handle));
+///
+/// listor.remove(handle);
+///
+/// // The slot may be reused, but the stale handle no longer resolves.
+/// listor.push_back(6).unwrap();
+/// assert_eq!(None, listor.get(handle));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Handle {
+    index: usize,
+    generation: u64,
+    /// Epoch of the owning listor when the handle was minted. Compaction
+    /// renumbers every slot and bumps the listor epoch, so handles from a
+    /// previous epoch never resolve.
+    epoch: u64,
+}
+
+impl Handle {
+    /// Returns the raw index this handle points at.
+    ///
+    /// The index can be passed to the `*_by_index` family of methods, but
+    /// carries no generation information, so a caller opting into it takes on
+    /// the responsibility of not using a stale slot.
+    pub fn index(&self) -> usize {
+        self.index
+    }
 }
 
 pub struct Listor<T> {
@@ -21,11 +69,35 @@ pub struct Listor<T> {
     tail: usize,
     /// False if the listor can grow.
     bounded: bool,
+    /// Bumped by compaction to invalidate every outstanding handle at once.
+    epoch: u64,
 }
 
 pub struct Iter<'a, T> {
     listor: &'a Listor<T>,
-    current: usize,
+    /// Index of the next element yielded from the front.
+    front: usize,
+    /// Index of the next element yielded from the back.
+    back: usize,
+    /// Number of elements still to yield; the two cursors have crossed once
+    /// this reaches zero, so it doubles as the termination condition.
+    remaining: usize,
+}
+
+/// A mutable iterator over the elements of a [`Listor`], front to back.
+pub struct IterMut<'a, T> {
+    /// Base pointer into the backing `Vec`; nodes are addressed by index so
+    /// that each step hands out a `&mut T` disjoint from the others.
+    elements: *mut Node<T>,
+    front: usize,
+    back: usize,
+    remaining: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+/// An owning iterator over the elements of a [`Listor`], front to back.
+pub struct IntoIter<T> {
+    listor: Listor<T>,
 }
 
 impl<T> Default for Listor<T> {
@@ -42,12 +114,14 @@ impl<T> Listor<T> {
                     data: Entry::Vacant,
                     prev: i.saturating_sub(1),
                     next: i.saturating_add(1).min(capacity - 1),
+                    generation: 0,
                 })
                 .collect(),
             count: 0,
             head: 0,
             tail: 0,
             bounded,
+            epoch: 0,
         }
     }
 
@@ -86,17 +160,160 @@ impl<T> Listor<T> {
     pub fn clear(&mut self) {
         let max_next = self.elements.len() - 1;
         for (idx, node) in self.elements.iter_mut().enumerate() {
-            *node = Node {
-                data: Entry::Vacant,
-                prev: idx.saturating_sub(1),
-                next: idx.saturating_add(1).min(max_next),
-            };
+            // Keep the generation counter monotonic so handles handed out before
+            // the clear do not resolve against a slot that is later reused.
+            node.data = Entry::Vacant;
+            node.prev = idx.saturating_sub(1);
+            node.next = idx.saturating_add(1).min(max_next);
         }
         self.count = 0;
         self.head = 0;
         self.tail = 0;
     }
 
+    /// Returns the number of slots the backing storage can hold without
+    /// growing, i.e. the count of occupied and vacant entries together.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use listor::Listor;
+    ///
+    /// let mut listor = Listor::<usize>::new();
+    /// assert_eq!(0, listor.capacity());
+    /// listor.reserve(4);
+    /// assert_eq!(4, listor.capacity());
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Pre-extends the vacant free list by `additional` slots so that the next
+    /// `additional` insertions reuse a slot instead of reallocating.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use listor::Listor;
+    ///
+    /// let mut listor = Listor::new();
+    /// listor.reserve(2);
+    ///
+    /// let cap = listor.capacity();
+    /// let _ = listor.push_back(1);
+    /// let _ = listor.push_back(2);
+    /// // The reserved slots were reused, so no growth happened.
+    /// assert_eq!(cap, listor.capacity());
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        if additional == 0 {
+            return;
+        }
+
+        let base = self.elements.len();
+        if base == 0 {
+            // Nothing to link to yet: lay out a fresh chain exactly as `create`.
+            self.elements = (0..additional)
+                .map(|i| Node {
+                    data: Entry::Vacant,
+                    prev: i.saturating_sub(1),
+                    next: i.saturating_add(1).min(additional - 1),
+                    generation: 0,
+                })
+                .collect();
+            return;
+        }
+
+        // Append the new vacant slots to the end of the existing chain.
+        let mut end = self.tail;
+        while self.elements[end].next != end {
+            end = self.elements[end].next;
+        }
+        self.elements[end].next = base;
+
+        for k in 0..additional {
+            let idx = base + k;
+            self.elements.push(Node {
+                data: Entry::Vacant,
+                prev: if k == 0 { end } else { idx - 1 },
+                next: if k == additional - 1 { idx } else { idx + 1 },
+                generation: 0,
+            });
+        }
+    }
+
+    /// Rebuilds the backing storage so that occupied nodes are dense and in
+    /// front-to-back iteration order, dropping all trailing vacant slots.
+    ///
+    /// Compaction renumbers every node, so it returns a mapping from each old
+    /// raw index to its new one (`None` for slots that were vacant and thus
+    /// dropped). All handles and raw indices obtained before the call are
+    /// invalidated; translate raw indices through the returned mapping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use listor::Listor;
+    ///
+    /// let mut listor = Listor::new();
+    /// let a = listor.push_back(1).unwrap();
+    /// let b = listor.push_back(2).unwrap();
+    /// listor.remove(a);
+    ///
+    /// let mapping = listor.compact();
+    /// assert_eq!(1, listor.capacity());
+    /// assert_eq!(Some(0), mapping[b.index()]);
+    /// assert_eq!(Some(&2), listor.get_by_index(0));
+    /// ```
+    pub fn compact(&mut self) -> Vec<Option<usize>> {
+        let mut mapping = vec![None; self.elements.len()];
+        let mut compacted = Vec::with_capacity(self.count);
+
+        let mut chain = self.occupied_head();
+        while let Some(idx) = chain {
+            let next = self.next_index(idx);
+            let new_idx = compacted.len();
+            mapping[idx] = Some(new_idx);
+
+            let node = &mut self.elements[idx];
+            compacted.push(Node {
+                data: std::mem::replace(&mut node.data, Entry::Vacant),
+                // Links are fixed up below once the final length is known.
+                prev: 0,
+                next: 0,
+                generation: node.generation,
+            });
+
+            chain = next;
+        }
+
+        let last = compacted.len().saturating_sub(1);
+        for (idx, node) in compacted.iter_mut().enumerate() {
+            node.prev = idx.saturating_sub(1);
+            node.next = idx.saturating_add(1).min(last);
+        }
+
+        self.elements = compacted;
+        self.head = 0;
+        self.tail = last;
+        // Renumbering invalidates every outstanding handle: bumping the epoch
+        // makes a stale `(index, generation)` pair fail validation even if it
+        // happens to land on an occupied slot with a matching generation.
+        self.epoch += 1;
+
+        mapping
+    }
+
+    /// Compacts the listor and releases the backing allocation down to the
+    /// number of occupied entries.
+    ///
+    /// Like [`compact`](Self::compact) this invalidates previously returned
+    /// handles and raw indices.
+    pub fn shrink_to_fit(&mut self) {
+        self.compact();
+        self.elements.shrink_to_fit();
+    }
+
     /// Returns the index where the next inserted item will be placed.
     ///
     /// # Example
@@ -162,6 +379,7 @@ impl<T> Listor<T> {
                 data: Entry::Vacant,
                 prev: self.tail,
                 next: idx,
+                generation: 0,
             });
 
             self.count += 1;
@@ -169,7 +387,23 @@ impl<T> Listor<T> {
         }
     }
 
-    /// Pushes an element to the back of the list and returns the index at which it can be accessed.
+    /// Marks a freshly allocated slot as occupied, bumps its generation and
+    /// returns a [`Handle`] pointing at it.
+    fn occupy(&mut self, idx: usize, item: T) -> Handle {
+        let epoch = self.epoch;
+        let node = &mut self.elements[idx];
+        node.generation += 1;
+        node.data = Entry::Occupied(item);
+
+        Handle {
+            index: idx,
+            generation: node.generation,
+            epoch,
+        }
+    }
+
+    /// Pushes an element to the back of the list and returns a [`Handle`] with
+    /// which it can be accessed.
     ///
     /// # Example
     ///
@@ -177,16 +411,16 @@ impl<T> Listor<T> {
     /// use listor::Listor;
     ///
     /// let mut listor = Listor::new();
-    /// let idx = listor.push_back(5).unwrap();
-    /// assert_eq!(5, listor[idx]);
+    /// let handle = listor.push_back(5).unwrap();
+    /// assert_eq!(5, listor[handle]);
     /// ```
     ///
     /// ```rust
     /// use listor::Listor;
     ///
     /// let mut listor = Listor::bounded(2);
-    /// assert_eq!(Ok(0), listor.push_back(5));
-    /// assert_eq!(Ok(1), listor.push_back(6));
+    /// assert!(listor.push_back(5).is_ok());
+    /// assert!(listor.push_back(6).is_ok());
     /// assert_eq!(Err(7), listor.push_back(7));
     /// ```
     ///
@@ -215,21 +449,20 @@ impl<T> Listor<T> {
     /// assert_eq!(Some(7), listor.pop_front());
     /// assert_eq!(None, listor.pop_front());
     /// ```
-    pub fn push_back(&mut self, item: T) -> Result<usize, T> {
+    pub fn push_back(&mut self, item: T) -> Result<Handle, T> {
         match self.allocate() {
             Some(idx) => {
                 // The allocated element is guaranteed to be our tail.
                 self.tail = idx;
-                self.elements[idx].data = Entry::Occupied(item);
-
-                Ok(idx)
+                Ok(self.occupy(idx, item))
             }
 
             None => Err(item),
         }
     }
 
-    /// Pushes an element to the front of the list and returns the index at which it can be accessed.
+    /// Pushes an element to the front of the list and returns a [`Handle`] with
+    /// which it can be accessed.
     ///
     /// # Example
     ///
@@ -237,16 +470,16 @@ impl<T> Listor<T> {
     /// use listor::Listor;
     ///
     /// let mut listor = Listor::new();
-    /// let idx = listor.push_front(5).unwrap();
-    /// assert_eq!(5, listor[idx]);
+    /// let handle = listor.push_front(5).unwrap();
+    /// assert_eq!(5, listor[handle]);
     /// ```
     ///
     /// ```rust
     /// use listor::Listor;
     ///
     /// let mut listor = Listor::bounded(2);
-    /// assert_eq!(Ok(0), listor.push_front(5));
-    /// assert_eq!(Ok(1), listor.push_front(6));
+    /// assert!(listor.push_front(5).is_ok());
+    /// assert!(listor.push_front(6).is_ok());
     /// assert_eq!(Err(7), listor.push_front(7));
     /// ```
     ///
@@ -273,10 +506,10 @@ impl<T> Listor<T> {
     /// assert_eq!(Some(6), listor.pop_front());
     /// assert_eq!(Some(5), listor.pop_front());
     /// ```
-    pub fn push_front(&mut self, item: T) -> Result<usize, T> {
+    pub fn push_front(&mut self, item: T) -> Result<Handle, T> {
         match self.allocate() {
             Some(idx) => {
-                self.elements[idx].data = Entry::Occupied(item);
+                let handle = self.occupy(idx, item);
 
                 if idx != self.head {
                     self.remove_node(idx);
@@ -284,7 +517,7 @@ impl<T> Listor<T> {
 
                     self.head = idx;
                 }
-                Ok(idx)
+                Ok(handle)
             }
 
             None => Err(item),
@@ -352,6 +585,70 @@ impl<T> Listor<T> {
         self.elements[prev].next = idx;
     }
 
+    /// Index of the occupied element following `idx`, or `None` if `idx` is the
+    /// tail.
+    ///
+    /// The tail's `next` link points into the vacant free chain (removed nodes
+    /// are spliced in after the tail), so the logical end is detected via the
+    /// `tail` field rather than the `next == self` sentinel.
+    fn next_index(&self, idx: usize) -> Option<usize> {
+        let node = self.elements.get(idx)?;
+        if idx == self.tail {
+            None
+        } else {
+            Some(node.next)
+        }
+    }
+
+    /// Index of the occupied element preceding `idx`, or `None` if `idx` is the
+    /// head.
+    fn prev_index(&self, idx: usize) -> Option<usize> {
+        let node = self.elements.get(idx)?;
+        if idx == self.head {
+            None
+        } else {
+            Some(node.prev)
+        }
+    }
+
+    /// Allocates a slot and splices it directly after `prev`, keeping `tail`
+    /// up to date. Returns `Err` only when a bounded listor is full.
+    fn insert_after_index(&mut self, prev: usize, item: T) -> Result<Handle, T> {
+        let idx = match self.allocate() {
+            Some(idx) => idx,
+            None => return Err(item),
+        };
+        let handle = self.occupy(idx, item);
+
+        // Detach the freshly allocated slot from the vacant chain before
+        // splicing it into its new interior position.
+        self.remove_node(idx);
+        self.insert_after(idx, prev);
+
+        if prev == self.tail {
+            self.tail = idx;
+        }
+        Ok(handle)
+    }
+
+    /// Allocates a slot and splices it directly before `next`, keeping `head`
+    /// up to date. Returns `Err` only when a bounded listor is full.
+    fn insert_before_index(&mut self, next: usize, item: T) -> Result<Handle, T> {
+        let idx = match self.allocate() {
+            Some(idx) => idx,
+            None => return Err(item),
+        };
+        let handle = self.occupy(idx, item);
+
+        self.remove_node(idx);
+        self.insert_before(idx, next);
+
+        if next == self.head {
+            self.head = idx;
+        }
+        Ok(handle)
+    }
+
     /// Pops an element off the back of the list.
     ///
     /// # Example
@@ -368,7 +665,7 @@ impl<T> Listor<T> {
     /// assert_eq!(None, listor.pop_back());
     /// ```
     pub fn pop_back(&mut self) -> Option<T> {
-        self.remove(self.tail)
+        self.remove_by_index(self.tail)
     }
 
     /// Pops an element off the beginning of the list.
@@ -387,7 +684,7 @@ impl<T> Listor<T> {
     /// assert_eq!(None, listor.pop_front());
     /// ```
     pub fn pop_front(&mut self) -> Option<T> {
-        self.remove(self.head)
+        self.remove_by_index(self.head)
     }
 
     /// Returns the number of occupied entries.
@@ -419,26 +716,70 @@ impl<T> Listor<T> {
         self.count
     }
 
-    /// Removes an element from the list.
+    /// Returns `true` if there are no occupied entries.
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use listor::Listor;
     ///
     /// let mut listor = Listor::new();
+    /// assert!(listor.is_empty());
+    /// listor.push_back(5);
+    /// assert!(!listor.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Removes an element from the list, addressed by a [`Handle`].
+    ///
+    /// Returns `None` if the element has already been removed, or if the
+    /// handle is stale, i.e. its slot has been reused (generation mismatch) or
+    /// the listor has since been compacted (epoch mismatch).
+    ///
+    /// ```rust
+    /// use listor::Listor;
     ///
-    /// assert_eq!(None, listor.remove(4));
+    /// let mut listor = Listor::new();
     ///
     /// listor.push_back(5);
-    /// let idx = listor.push_back(6).unwrap();
+    /// let handle = listor.push_back(6).unwrap();
     /// listor.push_back(7);
     ///
-    /// assert_eq!(Some(6), listor.remove(idx));
+    /// assert_eq!(Some(6), listor.remove(handle));
+    /// // The handle is now stale.
+    /// assert_eq!(None, listor.remove(handle));
     ///
     /// assert_eq!(Some(5), listor.pop_front());
     /// assert_eq!(Some(7), listor.pop_front());
     /// assert_eq!(None, listor.pop_front());
     /// ```
-    pub fn remove(&mut self, idx: usize) -> Option<T> {
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        match self.elements.get(handle.index) {
+            Some(node) if handle.epoch == self.epoch && node.generation == handle.generation => {
+                self.remove_by_index(handle.index)
+            }
+            _ => None,
+        }
+    }
+
+    /// Removes the element at a raw index, ignoring generations.
+    ///
+    /// This is the opt-out fast path for callers that manage slot reuse
+    /// themselves; prefer [`remove`](Self::remove) when working with handles.
+    ///
+    /// ```rust
+    /// use listor::Listor;
+    ///
+    /// let mut listor = Listor::new();
+    ///
+    /// assert_eq!(None, listor.remove_by_index(4));
+    ///
+    /// let handle = listor.push_back(5).unwrap();
+    /// assert_eq!(Some(5), listor.remove_by_index(handle.index()));
+    /// ```
+    pub fn remove_by_index(&mut self, idx: usize) -> Option<T> {
         if let Some(node) = self.elements.get_mut(idx) {
             match std::mem::replace(&mut node.data, Entry::Vacant) {
                 Entry::Vacant => None,
@@ -484,7 +825,7 @@ impl<T> Listor<T> {
     /// assert_eq!(Some(&6), listor.peek_front());
     /// ```
     pub fn peek_front(&self) -> Option<&T> {
-        self.get(self.head)
+        self.get_by_index(self.head)
     }
 
     /// Returns a reference to the last value.
@@ -507,19 +848,52 @@ impl<T> Listor<T> {
     /// assert_eq!(Some(&5), listor.peek_back());
     /// ```
     pub fn peek_back(&self) -> Option<&T> {
-        self.get(self.tail)
+        self.get_by_index(self.tail)
+    }
+
+    /// Returns a reference to the value behind a [`Handle`].
+    ///
+    /// Returns `None` if the handle is stale, i.e. its slot has been reused
+    /// (generation mismatch) or the listor has since been compacted (epoch
+    /// mismatch).
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        let node = self.elements.get(handle.index)?;
+        if handle.epoch != self.epoch || node.generation != handle.generation {
+            return None;
+        }
+        match node.data {
+            Entry::Vacant => None,
+            Entry::Occupied(ref element) => Some(element),
+        }
+    }
+
+    /// Returns a mutable reference to the value behind a [`Handle`].
+    ///
+    /// Returns `None` if the handle is stale, i.e. its slot has been reused
+    /// (generation mismatch) or the listor has since been compacted (epoch
+    /// mismatch).
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+        let epoch = self.epoch;
+        let node = self.elements.get_mut(handle.index)?;
+        if handle.epoch != epoch || node.generation != handle.generation {
+            return None;
+        }
+        match node.data {
+            Entry::Vacant => None,
+            Entry::Occupied(ref mut element) => Some(element),
+        }
     }
 
-    /// Returns a reference to the indexed value.
-    pub fn get(&self, idx: usize) -> Option<&T> {
+    /// Returns a reference to the value at a raw index, ignoring generations.
+    pub fn get_by_index(&self, idx: usize) -> Option<&T> {
         match self.elements.get(idx)?.data {
             Entry::Vacant => None,
             Entry::Occupied(ref element) => Some(element),
         }
     }
 
-    /// Returns a mutable reference to the indexed value.
-    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+    /// Returns a mutable reference to the value at a raw index, ignoring generations.
+    pub fn get_mut_by_index(&mut self, idx: usize) -> Option<&mut T> {
         match self.elements.get_mut(idx)?.data {
             Entry::Vacant => None,
             Entry::Occupied(ref mut element) => Some(element),
@@ -547,86 +921,895 @@ impl<T> Listor<T> {
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             listor: self,
-            current: self.head,
+            front: self.head,
+            back: self.tail,
+            remaining: self.count,
         }
     }
-}
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+    /// Iterate mutably over the elements, from front to back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use listor::Listor;
+    ///
+    /// let mut listor = Listor::new();
+    ///
+    /// listor.push_back(5);
+    /// listor.push_back(6);
+    ///
+    /// for value in listor.iter_mut() {
+    ///     *value += 1;
+    /// }
+    ///
+    /// assert_eq!(vec![&6, &7], listor.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            elements: self.elements.as_mut_ptr(),
+            front: self.head,
+            back: self.tail,
+            remaining: self.count,
+            _marker: PhantomData,
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(node) = self.listor.elements.get(self.current) {
-            self.current = if node.next == self.current {
-                self.listor.len()
-            } else {
-                node.next
-            };
+    /// Splits the listor in two, returning a new listor containing every
+    /// element from raw index `idx` to the tail.
+    ///
+    /// After the call the original listor is relinked so that `idx`'s
+    /// predecessor becomes the new tail. Because nodes live in a `Vec` rather
+    /// than being individually owned, the detached elements are moved into a
+    /// freshly numbered backing; handles and raw indices from before the split
+    /// are only valid within the listor that still owns their element.
+    ///
+    /// Each listor owns an independent backing `Vec`, so the split cannot be a
+    /// pointer-only relink: it runs in `O(n)` over the detached tail, moving
+    /// each element across backings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use listor::Listor;
+    ///
+    /// let mut listor = Listor::new();
+    /// let _ = listor.push_back(1);
+    /// let mid = listor.push_back(2).unwrap();
+    /// let _ = listor.push_back(3);
+    ///
+    /// let tail = listor.split_off(mid.index());
+    ///
+    /// assert_eq!(vec![&1], listor.iter().collect::<Vec<_>>());
+    /// assert_eq!(vec![&2, &3], tail.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn split_off(&mut self, idx: usize) -> Listor<T> {
+        let mut detached = Listor::new();
+
+        if self.occupied_index(idx).is_none() {
+            return detached;
+        }
+
+        // Collect the tail run up front: removing relinks the chain, but the
+        // captured indices stay valid because we only read each one once.
+        let mut indices = vec![idx];
+        let mut cursor = idx;
+        while let Some(next) = self.next_index(cursor) {
+            indices.push(next);
+            cursor = next;
+        }
 
-            match &node.data {
-                Entry::Occupied(data) => Some(data),
-                _ => None,
+        for i in indices {
+            if let Some(item) = self.remove_by_index(i) {
+                let _ = detached.push_back(item);
             }
-        } else {
-            None
         }
+
+        detached
     }
-}
 
-impl<T> Index<usize> for Listor<T> {
-    type Output = T;
+    /// Moves the elements of `other` onto the back of this listor, leaving
+    /// `other` empty.
+    ///
+    /// The elements are re-homed into this listor's backing, so indices and
+    /// handles that referred into `other` no longer apply afterwards. If this
+    /// listor is bounded and fills up mid-move, the elements that did not fit
+    /// are left in `other` in their original order.
+    ///
+    /// Because the two listors own independent backing `Vec`s, this is an
+    /// `O(other.len())` move across backings rather than an `O(1)` relink of
+    /// boundary links.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use listor::Listor;
+    ///
+    /// let mut a = Listor::new();
+    /// let _ = a.push_back(1);
+    /// let _ = a.push_back(2);
+    ///
+    /// let mut b = Listor::new();
+    /// let _ = b.push_back(3);
+    /// let _ = b.push_back(4);
+    ///
+    /// a.append(&mut b);
+    ///
+    /// assert_eq!(vec![&1, &2, &3, &4], a.iter().collect::<Vec<_>>());
+    /// assert_eq!(0, b.len());
+    /// ```
+    pub fn append(&mut self, other: &mut Listor<T>) {
+        while let Some(item) = other.pop_front() {
+            if let Err(item) = self.push_back(item) {
+                // A bounded target filled up: return the element that did not
+                // fit to the front of `other` and stop.
+                let _ = other.push_front(item);
+                break;
+            }
+        }
+    }
 
-    fn index(&self, index: usize) -> &Self::Output {
-        self.get(index).expect("Out of bounds access")
+    /// Splices the elements of `other` into this listor directly after raw
+    /// index `idx`, preserving their order and leaving `other` empty.
+    ///
+    /// If `idx` is not occupied the elements are appended to the back instead.
+    /// As with [`append`](Self::append), the spliced elements are re-homed and
+    /// their former indices no longer apply, and any elements that do not fit a
+    /// bounded target are left in `other`. Likewise it is an `O(other.len())`
+    /// move across the two independent backings, not an `O(1)` relink.
+    pub fn splice_after(&mut self, idx: usize, other: &mut Listor<T>) {
+        let mut anchor = self.occupied_index(idx);
+
+        while let Some(item) = other.pop_front() {
+            let inserted = match anchor {
+                Some(prev) => self.insert_after_index(prev, item),
+                None => self.push_back(item),
+            };
+
+            match inserted {
+                Ok(handle) => anchor = Some(handle.index()),
+                Err(item) => {
+                    let _ = other.push_front(item);
+                    break;
+                }
+            }
+        }
     }
-}
 
-impl<T> IndexMut<usize> for Listor<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.get_mut(index).expect("Out of bounds access")
+    /// Returns a read-only cursor positioned at the front element.
+    ///
+    /// On an empty listor the cursor starts on the "ghost" position between the
+    /// ends.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            listor: self,
+            current: self.occupied_head(),
+        }
     }
-}
 
-#[cfg(test)]
-mod test {
-    use crate::Listor;
+    /// Returns a read-only cursor positioned at the back element.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            listor: self,
+            current: self.occupied_tail(),
+        }
+    }
 
-    #[test]
-    fn test_unbounded_reuses_indexes() {
-        let mut listor = Listor::new();
+    /// Returns a read-only cursor positioned at the element with raw index
+    /// `idx`, or on the ghost position if the slot is vacant.
+    pub fn cursor(&self, idx: usize) -> Cursor<'_, T> {
+        Cursor {
+            listor: self,
+            current: self.occupied_index(idx),
+        }
+    }
 
-        let _ = listor.push_back(4);
-        let idx = listor.push_back(5).unwrap();
-        let _ = listor.push_back(6);
-        let _ = listor.push_back(7);
+    /// Returns a mutable cursor positioned at the front element.
+    ///
+    /// On an empty listor the cursor starts on the "ghost" position between the
+    /// ends.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.occupied_head();
+        CursorMut {
+            listor: self,
+            current,
+        }
+    }
 
-        // remove three elements
-        listor.pop_front();
-        listor.pop_back();
-        listor.remove(idx);
+    /// Returns a mutable cursor positioned at the back element.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.occupied_tail();
+        CursorMut {
+            listor: self,
+            current,
+        }
+    }
 
-        // re-insert three elements
-        let _ = listor.push_back(9);
-        let _ = listor.push_front(8);
-        let _ = listor.push_back(7);
+    /// Returns a mutable cursor positioned at the element with raw index `idx`,
+    /// or on the ghost position if the slot is vacant.
+    pub fn cursor_mut(&mut self, idx: usize) -> CursorMut<'_, T> {
+        let current = self.occupied_index(idx);
+        CursorMut {
+            listor: self,
+            current,
+        }
+    }
 
-        assert_eq!(4, listor.len());
+    fn occupied_head(&self) -> Option<usize> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.head)
+        }
+    }
 
-        for i in 0..4 {
-            assert!(listor.get(i).is_some())
+    fn occupied_tail(&self) -> Option<usize> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.tail)
         }
     }
 
-    #[test]
-    fn remove_preserves_iteration_order() {
-        let mut listor = Listor::new();
+    fn occupied_index(&self, idx: usize) -> Option<usize> {
+        match self.elements.get(idx) {
+            Some(node) if matches!(node.data, Entry::Occupied(_)) => Some(idx),
+            _ => None,
+        }
+    }
+}
 
-        let idx1 = listor.push_back(4).unwrap();
-        let _ = listor.push_back(5);
-        let _ = listor.push_back(6);
-        let idx2 = listor.push_back(7).unwrap();
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
 
-        listor.remove(idx1);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = &self.listor.elements[self.front];
+        self.front = node.next;
+        self.remaining -= 1;
+
+        match &node.data {
+            Entry::Occupied(data) => Some(data),
+            Entry::Vacant => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = &self.listor.elements[self.back];
+        self.back = node.prev;
+        self.remaining -= 1;
+
+        match &node.data {
+            Entry::Occupied(data) => Some(data),
+            Entry::Vacant => None,
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // Safety: `front` indexes a live node and the `remaining` counter keeps
+        // the front and back cursors from ever addressing the same node twice,
+        // so every `&mut T` handed out is disjoint.
+        unsafe {
+            let node = &mut *self.elements.add(self.front);
+            self.front = node.next;
+            self.remaining -= 1;
+
+            match &mut node.data {
+                Entry::Occupied(data) => Some(&mut *(data as *mut T)),
+                Entry::Vacant => None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // Safety: see `IterMut::next`.
+        unsafe {
+            let node = &mut *self.elements.add(self.back);
+            self.back = node.prev;
+            self.remaining -= 1;
+
+            match &mut node.data {
+                Entry::Occupied(data) => Some(&mut *(data as *mut T)),
+                Entry::Vacant => None,
+            }
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.listor.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.listor.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.listor.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for Listor<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { listor: self }
+    }
+}
+
+impl<T> FromIterator<T> for Listor<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut listor = Listor::new();
+        listor.extend(iter);
+        listor
+    }
+}
+
+impl<T> Extend<T> for Listor<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            let _ = self.push_back(item);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Listor<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Listor<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A read-only cursor over the elements of a [`Listor`].
+///
+/// A cursor points either at an element or at the "ghost" position that sits
+/// between the back and the front of the list, so that advancing past either
+/// end wraps around through the ghost, mirroring the `std` linked-list cursor.
+pub struct Cursor<'a, T> {
+    listor: &'a Listor<T>,
+    current: Option<usize>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Moves the cursor to the next element, wrapping from the tail to the
+    /// ghost position and from the ghost to the head.
+    pub fn move_next(&mut self) {
+        self.current = match self.current {
+            None => self.listor.occupied_head(),
+            Some(idx) => self.listor.next_index(idx),
+        };
+    }
+
+    /// Moves the cursor to the previous element, wrapping from the head to the
+    /// ghost position and from the ghost to the tail.
+    pub fn move_prev(&mut self) {
+        self.current = match self.current {
+            None => self.listor.occupied_tail(),
+            Some(idx) => self.listor.prev_index(idx),
+        };
+    }
+
+    /// Returns a reference to the current element, or `None` on the ghost.
+    pub fn current(&self) -> Option<&T> {
+        self.listor.get_by_index(self.current?)
+    }
+
+    /// Returns the raw index of the current element, or `None` on the ghost.
+    pub fn index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns a reference to the next element without moving the cursor.
+    pub fn peek_next(&self) -> Option<&T> {
+        let next = match self.current {
+            None => self.listor.occupied_head(),
+            Some(idx) => self.listor.next_index(idx),
+        };
+        self.listor.get_by_index(next?)
+    }
+
+    /// Returns a reference to the previous element without moving the cursor.
+    pub fn peek_prev(&self) -> Option<&T> {
+        let prev = match self.current {
+            None => self.listor.occupied_tail(),
+            Some(idx) => self.listor.prev_index(idx),
+        };
+        self.listor.get_by_index(prev?)
+    }
+}
+
+/// A cursor over the elements of a [`Listor`] that allows mutation.
+///
+/// In addition to the navigation offered by [`Cursor`], a `CursorMut` can
+/// splice elements in and out of the interior of the list in `O(1)`. Advancing
+/// past either end wraps through the ghost position, so `insert_after` on the
+/// ghost pushes to the front and `insert_before` on the ghost pushes to the
+/// back.
+///
+/// # Example
+///
+/// ```rust
+/// use listor::Listor;
+///
+/// let mut listor = Listor::new();
+/// listor.push_back(1);
+/// let two = listor.push_back(2).unwrap();
+/// listor.push_back(4);
+///
+/// let mut cursor = listor.cursor_mut(two.index());
+/// cursor.insert_after(3);
+///
+/// assert_eq!(vec![&1, &2, &3, &4], listor.iter().collect::<Vec<_>>());
+/// ```
+pub struct CursorMut<'a, T> {
+    listor: &'a mut Listor<T>,
+    current: Option<usize>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Moves the cursor to the next element, wrapping from the tail to the
+    /// ghost position and from the ghost to the head.
+    pub fn move_next(&mut self) {
+        self.current = match self.current {
+            None => self.listor.occupied_head(),
+            Some(idx) => self.listor.next_index(idx),
+        };
+    }
+
+    /// Moves the cursor to the previous element, wrapping from the head to the
+    /// ghost position and from the ghost to the tail.
+    pub fn move_prev(&mut self) {
+        self.current = match self.current {
+            None => self.listor.occupied_tail(),
+            Some(idx) => self.listor.prev_index(idx),
+        };
+    }
+
+    /// Returns a reference to the current element, or `None` on the ghost.
+    pub fn current(&self) -> Option<&T> {
+        self.listor.get_by_index(self.current?)
+    }
+
+    /// Returns a mutable reference to the current element, or `None` on the ghost.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.listor.get_mut_by_index(self.current?)
+    }
+
+    /// Returns the raw index of the current element, or `None` on the ghost.
+    pub fn index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns a reference to the next element without moving the cursor.
+    pub fn peek_next(&self) -> Option<&T> {
+        let next = match self.current {
+            None => self.listor.occupied_head(),
+            Some(idx) => self.listor.next_index(idx),
+        };
+        self.listor.get_by_index(next?)
+    }
+
+    /// Returns a reference to the previous element without moving the cursor.
+    pub fn peek_prev(&self) -> Option<&T> {
+        let prev = match self.current {
+            None => self.listor.occupied_tail(),
+            Some(idx) => self.listor.prev_index(idx),
+        };
+        self.listor.get_by_index(prev?)
+    }
+
+    /// Inserts an element after the current one, leaving the cursor in place.
+    ///
+    /// On the ghost position this pushes to the front of the list. Returns the
+    /// [`Handle`] of the inserted element, or `Err` if a bounded listor is full.
+    pub fn insert_after(&mut self, item: T) -> Result<Handle, T> {
+        match self.current {
+            Some(idx) => self.listor.insert_after_index(idx, item),
+            None => self.listor.push_front(item),
+        }
+    }
+
+    /// Inserts an element before the current one, leaving the cursor in place.
+    ///
+    /// On the ghost position this pushes to the back of the list. Returns the
+    /// [`Handle`] of the inserted element, or `Err` if a bounded listor is full.
+    pub fn insert_before(&mut self, item: T) -> Result<Handle, T> {
+        match self.current {
+            Some(idx) => self.listor.insert_before_index(idx, item),
+            None => self.listor.push_back(item),
+        }
+    }
+
+    /// Removes the current element and advances the cursor to the following one.
+    ///
+    /// When the removed element was the tail the cursor lands on the ghost
+    /// position. Returns the removed value, or `None` on the ghost.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let idx = self.current?;
+        let next = self.listor.next_index(idx);
+        let removed = self.listor.remove_by_index(idx);
+        self.current = next;
+        removed
+    }
+}
+
+impl<T> Index<Handle> for Listor<T> {
+    type Output = T;
+
+    fn index(&self, handle: Handle) -> &Self::Output {
+        self.get(handle).expect("Stale or out of bounds access")
+    }
+}
+
+impl<T> IndexMut<Handle> for Listor<T> {
+    fn index_mut(&mut self, handle: Handle) -> &mut Self::Output {
+        self.get_mut(handle).expect("Stale or out of bounds access")
+    }
+}
+
+impl<T> Index<usize> for Listor<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get_by_index(index).expect("Out of bounds access")
+    }
+}
+
+impl<T> IndexMut<usize> for Listor<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut_by_index(index).expect("Out of bounds access")
+    }
+}
+
+/// Number of element slots packed into a single unrolled block.
+const BLOCK_CAPACITY: usize = 8;
+
+/// A contiguous run of up to [`BLOCK_CAPACITY`] occupied slots, kept compacted
+/// to the front of the array.
+struct Block<T> {
+    slots: [Entry<T>; BLOCK_CAPACITY],
+    /// Number of occupied slots, which always occupy `slots[0..count]`.
+    count: usize,
+}
+
+impl<T> Block<T> {
+    fn new() -> Self {
+        Self {
+            slots: std::array::from_fn(|_| Entry::Vacant),
+            count: 0,
+        }
+    }
+
+    /// Appends to the block, returning the item back when the block is full.
+    fn push(&mut self, item: T) -> Result<(), T> {
+        if self.count == BLOCK_CAPACITY {
+            return Err(item);
+        }
+        self.slots[self.count] = Entry::Occupied(item);
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Removes the `local`th occupied slot, shifting the rest down to keep the
+    /// block compacted.
+    fn remove(&mut self, local: usize) -> Option<T> {
+        if local >= self.count {
+            return None;
+        }
+        let item = match std::mem::replace(&mut self.slots[local], Entry::Vacant) {
+            Entry::Occupied(item) => item,
+            Entry::Vacant => return None,
+        };
+        for i in local..self.count - 1 {
+            self.slots.swap(i, i + 1);
+        }
+        self.count -= 1;
+        Some(item)
+    }
+}
+
+/// A list that stores its elements in small contiguous blocks rather than one
+/// node per element. The blocks are kept in order in a `Vec` and are not linked
+/// to each other, so dropping or merging a block shifts the blocks after it.
+///
+/// Because iteration sweeps each block's backing array before moving on to the
+/// next block, a read-heavy workload touches far fewer cache lines
+/// than the index-chasing [`Listor`] once the backing storage has been churned
+/// by many insert/remove cycles. On removal a block that drops below half full
+/// is merged with or borrows from its successor, so every block except possibly
+/// the last stays at least half full.
+///
+/// Unlike [`Listor`], this type does not offer stable slot indices or
+/// generational handles: elements are addressed by logical position (the front
+/// element is `0`), and removing an element shifts the position of every
+/// element after it.
+///
+/// # Example
+///
+/// ```rust
+/// use listor::UnrolledListor;
+///
+/// let mut listor = UnrolledListor::new();
+/// listor.push_back(1);
+/// listor.push_back(2);
+/// listor.push_back(3);
+///
+/// listor.remove(1);
+///
+/// assert_eq!(vec![&1, &3], listor.iter().collect::<Vec<_>>());
+/// ```
+pub struct UnrolledListor<T> {
+    blocks: Vec<Block<T>>,
+    /// Total number of occupied slots across all blocks.
+    count: usize,
+}
+
+impl<T> Default for UnrolledListor<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> UnrolledListor<T> {
+    /// Creates a new, empty unrolled listor.
+    pub fn new() -> Self {
+        Self {
+            blocks: Vec::new(),
+            count: 0,
+        }
+    }
+
+    /// Returns the number of occupied entries.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if there are no occupied entries.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Removes all elements and drops every block; the capacity of the `Vec`
+    /// that held the blocks is kept.
+    pub fn clear(&mut self) {
+        self.blocks.clear();
+        self.count = 0;
+    }
+
+    /// Pushes an element to the back, filling the tail block before allocating
+    /// a new one.
+    pub fn push_back(&mut self, item: T) {
+        self.count += 1;
+
+        // `push` hands the item back when the tail block is full, so the
+        // spill-over simply seeds a fresh block.
+        let item = match self.blocks.last_mut() {
+            Some(last) => match last.push(item) {
+                Ok(()) => return,
+                Err(item) => item,
+            },
+            None => item,
+        };
+
+        let mut block = Block::new();
+        let _ = block.push(item);
+        self.blocks.push(block);
+    }
+
+    /// Locates the block and in-block offset holding logical position `index`.
+    fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        let mut remaining = index;
+        for (block_idx, block) in self.blocks.iter().enumerate() {
+            if remaining < block.count {
+                return Some((block_idx, remaining));
+            }
+            remaining -= block.count;
+        }
+        None
+    }
+
+    /// Returns a reference to the element at logical position `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (block_idx, local) = self.locate(index)?;
+        match self.blocks[block_idx].slots[local] {
+            Entry::Occupied(ref item) => Some(item),
+            Entry::Vacant => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element at logical position `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (block_idx, local) = self.locate(index)?;
+        match self.blocks[block_idx].slots[local] {
+            Entry::Occupied(ref mut item) => Some(item),
+            Entry::Vacant => None,
+        }
+    }
+
+    /// Removes and returns the element at logical position `index`.
+    ///
+    /// After the removal the emptied block is dropped. If a non-last block
+    /// falls below half full it is rebalanced against its successor — merged
+    /// when the two fit in one block, otherwise it borrows the successor's
+    /// first element — so every block except possibly the last stays at least
+    /// half full.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let (block_idx, local) = self.locate(index)?;
+        let item = self.blocks[block_idx].remove(local)?;
+        self.count -= 1;
+
+        if self.blocks[block_idx].count == 0 {
+            self.blocks.remove(block_idx);
+        } else {
+            self.rebalance(block_idx);
+        }
+
+        Some(item)
+    }
+
+    /// Restores the half-full invariant for `block_idx` after a removal dropped
+    /// it below [`BLOCK_CAPACITY`]` / 2`, by merging it with or borrowing from
+    /// its successor.
+    fn rebalance(&mut self, block_idx: usize) {
+        // The last block is exempt from the invariant, and a block that is
+        // still at least half full needs no work.
+        if block_idx + 1 >= self.blocks.len() || self.blocks[block_idx].count >= BLOCK_CAPACITY / 2 {
+            return;
+        }
+
+        if self.blocks[block_idx].count + self.blocks[block_idx + 1].count <= BLOCK_CAPACITY {
+            // The two fit together: fold the successor into this block.
+            let next = self.blocks.remove(block_idx + 1);
+            let block = &mut self.blocks[block_idx];
+            for slot in next.slots.into_iter().take(next.count) {
+                if let Entry::Occupied(item) = slot {
+                    block.slots[block.count] = Entry::Occupied(item);
+                    block.count += 1;
+                }
+            }
+        } else if let Some(item) = self.blocks[block_idx + 1].remove(0) {
+            // The successor has spare capacity above half: borrow its first
+            // element so both blocks end up at least half full.
+            let block = &mut self.blocks[block_idx];
+            block.slots[block.count] = Entry::Occupied(item);
+            block.count += 1;
+        }
+    }
+
+    /// Iterate over the elements, from front to back, sweeping each block's
+    /// backing array contiguously.
+    pub fn iter(&self) -> UnrolledIter<'_, T> {
+        UnrolledIter {
+            listor: self,
+            block: 0,
+            slot: 0,
+        }
+    }
+}
+
+/// A forward iterator over the elements of an [`UnrolledListor`].
+pub struct UnrolledIter<'a, T> {
+    listor: &'a UnrolledListor<T>,
+    block: usize,
+    slot: usize,
+}
+
+impl<'a, T> Iterator for UnrolledIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(block) = self.listor.blocks.get(self.block) {
+            if self.slot < block.count {
+                let slot = self.slot;
+                self.slot += 1;
+                if let Entry::Occupied(ref item) = block.slots[slot] {
+                    return Some(item);
+                }
+            } else {
+                self.block += 1;
+                self.slot = 0;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Listor;
+    use crate::UnrolledListor;
+
+    #[test]
+    fn test_unbounded_reuses_indexes() {
+        let mut listor = Listor::new();
+
+        let _ = listor.push_back(4);
+        let idx = listor.push_back(5).unwrap();
+        let _ = listor.push_back(6);
+        let _ = listor.push_back(7);
+
+        // remove three elements
+        listor.pop_front();
+        listor.pop_back();
+        listor.remove(idx);
+
+        // re-insert three elements
+        let _ = listor.push_back(9);
+        let _ = listor.push_front(8);
+        let _ = listor.push_back(7);
+
+        assert_eq!(4, listor.len());
+
+        for i in 0..4 {
+            assert!(listor.get_by_index(i).is_some())
+        }
+    }
+
+    #[test]
+    fn remove_preserves_iteration_order() {
+        let mut listor = Listor::new();
+
+        let idx1 = listor.push_back(4).unwrap();
+        let _ = listor.push_back(5);
+        let _ = listor.push_back(6);
+        let idx2 = listor.push_back(7).unwrap();
+
+        listor.remove(idx1);
         listor.remove(idx2);
 
         let _ = listor.push_back(8);
@@ -682,6 +1865,280 @@ mod test {
         assert_eq!(None, listor.pop_front());
     }
 
+    #[test]
+    fn stale_handle_does_not_alias_reused_slot() {
+        let mut listor = Listor::bounded(2);
+
+        let handle = listor.push_back(5).unwrap();
+        assert_eq!(Some(&5), listor.get(handle));
+
+        listor.remove(handle);
+
+        // The slot is reused, but the old handle must not resolve to the new
+        // element.
+        let reused = listor.push_back(6).unwrap();
+        assert_eq!(handle.index(), reused.index());
+        assert_eq!(None, listor.get(handle));
+        assert_eq!(Some(&6), listor.get(reused));
+    }
+
+    #[test]
+    fn cursor_inserts_into_the_interior() {
+        let mut listor = Listor::new();
+        let _ = listor.push_back(1);
+        let two = listor.push_back(2).unwrap();
+        let _ = listor.push_back(4);
+
+        let mut cursor = listor.cursor_mut(two.index());
+        assert_eq!(Some(&2), cursor.current());
+        assert_eq!(Some(&4), cursor.peek_next());
+        assert_eq!(Some(&1), cursor.peek_prev());
+        let _ = cursor.insert_after(3);
+
+        assert_eq!(
+            vec![&1, &2, &3, &4],
+            listor.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn cursor_ghost_wraps_around_the_ends() {
+        let mut listor = Listor::new();
+        let _ = listor.push_back(2);
+
+        let mut cursor = listor.cursor_front_mut();
+        assert_eq!(Some(&2), cursor.current());
+
+        // Step onto the ghost past the tail, then insert at either end.
+        cursor.move_next();
+        assert_eq!(None, cursor.current());
+        let _ = cursor.insert_after(1);
+        let _ = cursor.insert_before(3);
+
+        assert_eq!(vec![&1, &2, &3], listor.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn cursor_remove_current_advances() {
+        let mut listor = Listor::new();
+        let _ = listor.push_back(1);
+        let two = listor.push_back(2).unwrap();
+        let _ = listor.push_back(3);
+
+        let mut cursor = listor.cursor_mut(two.index());
+        assert_eq!(Some(2), cursor.remove_current());
+        assert_eq!(Some(&3), cursor.current());
+
+        assert_eq!(vec![&1, &3], listor.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iter_is_double_ended() {
+        let mut listor = Listor::new();
+        let _ = listor.push_back(1);
+        let _ = listor.push_back(2);
+        let _ = listor.push_back(3);
+        let _ = listor.push_back(4);
+
+        let mut iter = listor.iter();
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(Some(&4), iter.next_back());
+        assert_eq!(Some(&2), iter.next());
+        assert_eq!(Some(&3), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+    }
+
+    #[test]
+    fn into_iter_from_both_ends() {
+        let mut listor = Listor::new();
+        let _ = listor.push_back(1);
+        let _ = listor.push_back(2);
+        let _ = listor.push_back(3);
+
+        let mut iter = listor.into_iter();
+        assert_eq!(Some(1), iter.next());
+        assert_eq!(Some(3), iter.next_back());
+        assert_eq!(Some(2), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn iter_mut_yields_in_order() {
+        let mut listor = Listor::new();
+        let _ = listor.push_back(1);
+        let _ = listor.push_back(2);
+        let _ = listor.push_back(3);
+
+        for value in listor.iter_mut() {
+            *value *= 10;
+        }
+
+        assert_eq!(vec![&10, &20, &30], listor.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn from_iter_and_extend() {
+        let mut listor = Listor::from_iter([1, 2, 3]);
+        listor.extend([4, 5]);
+
+        assert_eq!(
+            vec![&1, &2, &3, &4, &5],
+            listor.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn split_off_detaches_the_tail_run() {
+        let mut listor = Listor::new();
+        let _ = listor.push_back(1);
+        let mid = listor.push_back(2).unwrap();
+        let _ = listor.push_back(3);
+        let _ = listor.push_back(4);
+
+        let tail = listor.split_off(mid.index());
+
+        assert_eq!(vec![&1], listor.iter().collect::<Vec<_>>());
+        assert_eq!(vec![&2, &3, &4], tail.iter().collect::<Vec<_>>());
+        assert_eq!(1, listor.len());
+        assert_eq!(3, tail.len());
+    }
+
+    #[test]
+    fn splice_after_inserts_in_order() {
+        let mut a = Listor::new();
+        let _ = a.push_back(1);
+        let two = a.push_back(2).unwrap();
+        let _ = a.push_back(5);
+
+        let mut b = Listor::from_iter([3, 4]);
+        a.splice_after(two.index(), &mut b);
+
+        assert_eq!(vec![&1, &2, &3, &4, &5], a.iter().collect::<Vec<_>>());
+        assert_eq!(0, b.len());
+    }
+
+    #[test]
+    fn append_into_bounded_keeps_the_remainder() {
+        let mut a = Listor::bounded(3);
+        let _ = a.push_back(1);
+
+        let mut b = Listor::from_iter([2, 3, 4]);
+        a.append(&mut b);
+
+        // `a` took what fit; the rest stays in `b` in order.
+        assert_eq!(vec![&1, &2, &3], a.iter().collect::<Vec<_>>());
+        assert_eq!(vec![&4], b.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn unrolled_push_and_iterate() {
+        let mut listor = UnrolledListor::new();
+        for i in 0..20 {
+            listor.push_back(i);
+        }
+
+        assert_eq!(20, listor.len());
+        assert_eq!((0..20).collect::<Vec<_>>(), listor.iter().copied().collect::<Vec<_>>());
+        assert_eq!(Some(&7), listor.get(7));
+    }
+
+    #[test]
+    fn unrolled_remove_merges_half_empty_blocks() {
+        let mut listor = UnrolledListor::new();
+        // Two full-ish blocks: 8 in the first, 4 in the second.
+        for i in 0..12 {
+            listor.push_back(i);
+        }
+
+        // Drain the first block below half full; the two blocks then fit
+        // together, which triggers a merge.
+        for _ in 0..5 {
+            listor.remove(0);
+        }
+
+        assert_eq!(1, listor.blocks.len());
+        assert_eq!(7, listor.len());
+        assert_eq!(
+            vec![5, 6, 7, 8, 9, 10, 11],
+            listor.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn unrolled_remove_borrows_to_keep_blocks_half_full() {
+        let mut listor = UnrolledListor::new();
+        // Two full blocks: [0..8) and [8..16).
+        for i in 0..16 {
+            listor.push_back(i);
+        }
+
+        // Drain the first block below half; the two full blocks cannot merge,
+        // so the first borrows from the second instead.
+        for _ in 0..5 {
+            listor.remove(0);
+        }
+
+        assert!(listor.blocks[0].count >= crate::BLOCK_CAPACITY / 2);
+        assert_eq!(11, listor.len());
+        assert_eq!(
+            (5..16).collect::<Vec<_>>(),
+            listor.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn reserve_reuses_slots_without_growing() {
+        let mut listor = Listor::new();
+        listor.reserve(3);
+        assert_eq!(3, listor.capacity());
+
+        for i in 0..3 {
+            let _ = listor.push_back(i);
+        }
+
+        // All three landed in the reserved slots.
+        assert_eq!(3, listor.capacity());
+        assert_eq!(vec![&0, &1, &2], listor.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn compact_densifies_and_remaps() {
+        let mut listor = Listor::new();
+        let _ = listor.push_back(1);
+        let b = listor.push_back(2).unwrap();
+        let c = listor.push_back(3).unwrap();
+        let _ = listor.push_back(4);
+
+        listor.pop_front();
+        listor.remove(c);
+
+        let mapping = listor.compact();
+
+        // Two elements survive, densely packed, in iteration order.
+        assert_eq!(2, listor.len());
+        assert_eq!(2, listor.capacity());
+        assert_eq!(vec![&2, &4], listor.iter().collect::<Vec<_>>());
+        assert_eq!(Some(0), mapping[b.index()]);
+        assert_eq!(Some(&2), listor.get_by_index(0));
+    }
+
+    #[test]
+    fn compact_invalidates_stale_handles() {
+        let mut listor = Listor::new();
+        let a = listor.push_back(111).unwrap();
+        let _ = listor.push_back(222);
+        listor.remove(a);
+
+        listor.compact();
+
+        // `a`'s old slot is now occupied by 222, but the handle must not alias
+        // it after a compaction.
+        assert_eq!(None, listor.get(a));
+        assert_eq!(None, listor.get_mut(a));
+        assert_eq!(None, listor.remove(a));
+    }
+
     #[test]
     fn pop_front_preserves_iteration_order() {
         let mut listor = Listor::new();